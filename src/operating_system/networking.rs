@@ -12,11 +12,42 @@
 //! | [**Win32\_NTDomain**](/previous-versions/windows/desktop/cimwin32a/win32-ntdomain)                                        | Instance class<br/> Represents a Windows NT domain.<br/>                                                             |
 //! | [**Win32\_PingStatus**](/previous-versions/windows/desktop/wmipicmp/win32-pingstatus)                               | Instance class<br/> Represents the values returned by the standard **ping** command.<br/>                            |
 //! | [**Win32\_ProtocolBinding**](win32-protocolbinding.md)                          | Association class<br/> Relates a system-level driver, network protocol, and network adapter.<br/>                    |
+//!
+//! `Win32_IP4RouteTable` and `Win32_IP4PersistedRouteTable` are explicitly IPv4-only, so this module
+//! also ships [`IP6RouteTables`], a sibling subsystem sourced from the native IP Helper API
+//! (`GetIpForwardTable2`) rather than WMI, covering IPv6 routes.
 
 use crate::update;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::Arc;
+use std::thread::JoinHandle;
 use std::time::SystemTime;
-use wmi::{COMLibrary, WMIConnection, WMIDateTime};
+use windows::core::BSTR;
+use windows::Win32::Foundation::E_UNEXPECTED;
+use windows::Win32::NetworkManagement::IpHelper::{
+    FreeMibTable, GetIpForwardTable2, MIB_IPFORWARD_ROW2, MIB_IPFORWARD_TABLE2,
+};
+use windows::Win32::Networking::WinSock::{AF_INET, AF_INET6, SOCKADDR_INET};
+use windows::Win32::System::Com::VARIANT;
+use windows::Win32::System::Wmi::{
+    IWbemClassObject, WBEM_FLAG_CREATE_ONLY, WBEM_FLAG_UPDATE_ONLY,
+};
+use wmi::{from_wbem_class_obj, COMLibrary, FilterValue, WMIConnection, WMIDateTime};
+
+/// How long the [`RouteEventStream`] worker blocks on a single `IEnumWbemClassObject::Next` call
+/// before rechecking whether the caller asked it to stop.
+const NOTIFICATION_POLL_TIMEOUT_MS: i32 = 500;
+
+/// Escapes a value for safe interpolation into a single-quoted or double-quoted WQL string
+/// literal, so a `Destination`/`Mask`/`NextHop`/`Address` containing a quote can't break out of the
+/// literal or inject additional WQL.
+fn escape_wql_literal(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('\'', "\\'").replace('"', "\\\"")
+}
 
 /// Represents the state of Windows IP4PersistedRouteTables
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -40,6 +71,117 @@ pub struct IP4RouteTables {
 
 update!(IP4RouteTables, ip4_route_tables);
 
+/// Represents the state of Windows IP6RouteTables.
+///
+/// Unlike [`IP4RouteTables`], this is populated from the native IP Helper API
+/// (`GetIpForwardTable2`) instead of WMI, since `Win32_IP4RouteTable` only covers IPv4. This is
+/// IPv6-only: `GetIpForwardTable2` is queried with `AF_INET6`, which filters out IPv4 rows rather
+/// than returning a dual-stack table.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct IP6RouteTables {
+    /// Represents sequence of Windows `IP6RouteTables`
+    pub ip6_route_tables: Vec<IP6RouteTable>,
+    /// When was the record last updated
+    pub last_updated: SystemTime,
+}
+
+impl IP6RouteTables {
+    /// Creates a new snapshot of the IPv6 routing table.
+    pub fn new() -> Result<Self, windows::core::Error> {
+        Ok(Self {
+            ip6_route_tables: IP6RouteTable::query()?,
+            last_updated: SystemTime::now(),
+        })
+    }
+
+    /// Refreshes this snapshot in place.
+    pub fn update(&mut self) -> Result<(), windows::core::Error> {
+        self.ip6_route_tables = IP6RouteTable::query()?;
+        self.last_updated = SystemTime::now();
+        Ok(())
+    }
+}
+
+/// A single IPv6 route, sourced from a `MIB_IPFORWARD_ROW2` row returned by
+/// `GetIpForwardTable2(AF_INET6, ...)`.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct IP6RouteTable {
+    /// Destination IP address of this route.
+    pub destination_prefix: IpAddr,
+    /// Length, in bits, of the destination prefix.
+    pub prefix_length: u8,
+    /// IP address of the next hop of this route, or `None` for an on-link route that reports no
+    /// real next hop (`NextHop`'s address family is unset).
+    pub next_hop: Option<IpAddr>,
+    /// Index of the local interface used for this route.
+    pub interface_index: u32,
+    /// Locally unique identifier (LUID) of the network interface used for this route.
+    pub interface_luid: u64,
+    /// Routing metric for this route.
+    pub metric: u32,
+    /// Routing mechanism through which this route was learned (e.g. local, static, RIP, OSPF),
+    /// as defined by the `MIB_IPFORWARD_PROTO` enumeration. See [`IP6RouteTable::protocol`] for
+    /// the decoded [`RouteProtocol`].
+    pub protocol: u32,
+    /// How this route was learned, e.g. manually configured or from a router advertisement.
+    pub origin: u32,
+}
+
+impl IP6RouteTable {
+    /// Decodes [`IP6RouteTable::protocol`] into a [`RouteProtocol`], the same `MIB_IPFORWARD_PROTO`
+    /// enumeration [`Win32_IP4RouteTable::protocol`] decodes into, falling back to
+    /// [`RouteProtocol::Unknown`] for any value it doesn't cover.
+    pub fn protocol(&self) -> RouteProtocol {
+        RouteProtocol::from(self.protocol)
+    }
+
+    fn query() -> Result<Vec<Self>, windows::core::Error> {
+        unsafe {
+            let mut table: *mut MIB_IPFORWARD_TABLE2 = std::ptr::null_mut();
+            GetIpForwardTable2(AF_INET6, &mut table).ok()?;
+
+            let rows = std::slice::from_raw_parts((*table).Table.as_ptr(), (*table).NumEntries as usize);
+            let routes = rows.iter().map(Self::from_row).collect::<Result<Vec<_>, _>>();
+
+            FreeMibTable(table as *const _);
+
+            routes
+        }
+    }
+
+    fn from_row(row: &MIB_IPFORWARD_ROW2) -> Result<Self, windows::core::Error> {
+        let destination_prefix = sockaddr_inet_to_ip(&row.DestinationPrefix.Prefix).ok_or_else(|| {
+            windows::core::Error::new(E_UNEXPECTED, "DestinationPrefix has an unrecognized address family")
+        })?;
+
+        Ok(Self {
+            destination_prefix,
+            prefix_length: row.DestinationPrefix.PrefixLength,
+            next_hop: sockaddr_inet_to_ip(&row.NextHop),
+            interface_index: row.InterfaceIndex,
+            interface_luid: unsafe { row.InterfaceLuid.Value },
+            metric: row.Metric,
+            protocol: row.Protocol.0 as u32,
+            origin: row.Origin.0 as u32,
+        })
+    }
+}
+
+/// Converts a `SOCKADDR_INET` union into an owned [`IpAddr`], branching explicitly on the address
+/// family. Returns `None` for anything other than `AF_INET`/`AF_INET6` (e.g. an unset/`AF_UNSPEC`
+/// family on an on-link route's `NextHop`) instead of guessing it's IPv4.
+fn sockaddr_inet_to_ip(addr: &SOCKADDR_INET) -> Option<IpAddr> {
+    unsafe {
+        match addr.si_family {
+            AF_INET => Some(IpAddr::V4(Ipv4Addr::from(
+                addr.Ipv4.sin_addr.S_un.S_addr.to_ne_bytes(),
+            ))),
+            AF_INET6 => Some(IpAddr::V6(Ipv6Addr::from(addr.Ipv6.sin6_addr.u.Byte))),
+            _ => None,
+        }
+    }
+}
+
 /// The Win32_IP4PersistedRouteTable WMI class represents persisted IP routes. By default, the routes 
 /// added to the routing table are not permanent. Rebooting the computer clears the routes from the 
 /// table. However, the following command makes the route persist after the computer is restarted: 
@@ -96,7 +238,153 @@ pub struct Win32_IP4PersistedRouteTable {
     pub Status: Option<String>,
 }
 
-/// The `Win32_IP4RouteTable` WMI class represents information that governs the routing of network data packets. 
+impl Win32_IP4PersistedRouteTable {
+    /// Adds a new persisted IP route (equivalent to `route -p add`) through the WMI `RouteProvider`'s
+    /// `PutInstance` support.
+    ///
+    /// `wmi::WMIConnection` only exposes read-oriented queries, so this spawns an instance of the
+    /// class and commits it through the raw `IWbemServices` COM pointer ([`WMIConnection::svc`])
+    /// directly, using `WBEM_FLAG_CREATE_ONLY` so a key tuple that already exists errors out
+    /// instead of silently overwriting the existing route's metric. The provider silently ignores
+    /// invalid values instead of erroring, so the new instance is re-queried by its key tuple
+    /// (`Destination`, `Mask`, `NextHop`) afterwards and an error is returned if the metric does
+    /// not match what was requested.
+    pub fn create(
+        conn: &WMIConnection,
+        destination: &str,
+        mask: &str,
+        next_hop: &str,
+        metric: i32,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        unsafe {
+            let mut class_obj = None;
+            conn.svc().GetObject(
+                &BSTR::from("Win32_IP4PersistedRouteTable"),
+                0,
+                None,
+                Some(&mut class_obj),
+                None,
+            )?;
+            let class_obj = class_obj.ok_or("Win32_IP4PersistedRouteTable class object not found")?;
+
+            let instance = class_obj.SpawnInstance(0)?;
+            put_string_property(&instance, "Destination", destination)?;
+            put_string_property(&instance, "Mask", mask)?;
+            put_string_property(&instance, "NextHop", next_hop)?;
+            put_i32_property(&instance, "Metric1", metric)?;
+
+            conn.svc()
+                .PutInstance(&instance, WBEM_FLAG_CREATE_ONLY.0, None, None)?;
+        }
+
+        Self::find_by_key(conn, destination, mask, next_hop)?
+            .filter(|route| route.Metric1 == Some(metric))
+            .ok_or_else(|| "RouteProvider rejected the new persisted route".into())
+    }
+
+    /// Removes this persisted route (equivalent to `route delete`) via the raw `IWbemServices`
+    /// `DeleteInstance` call ([`WMIConnection::svc`]).
+    pub fn delete(&self, conn: &WMIConnection) -> Result<(), Box<dyn std::error::Error>> {
+        let path = self.object_path()?;
+        unsafe {
+            conn.svc().DeleteInstance(&BSTR::from(path), 0, None, None)?;
+        }
+        Ok(())
+    }
+
+    /// Updates the metric of this persisted route via `PutInstance` on the raw `IWbemServices`
+    /// pointer ([`WMIConnection::svc`]), using `WBEM_FLAG_UPDATE_ONLY` so this fails instead of
+    /// creating a new route if the key tuple no longer exists.
+    ///
+    /// Because the provider leaves the original instance intact on a rejected value, the updated
+    /// instance is re-queried by its key tuple afterwards and an error is returned if the metric did
+    /// not actually change.
+    pub fn update_route(
+        &self,
+        conn: &WMIConnection,
+        metric: i32,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let (destination, mask, next_hop) = self.key()?;
+        let path = self.object_path()?;
+
+        unsafe {
+            let mut instance = None;
+            conn.svc()
+                .GetObject(&BSTR::from(path), 0, None, Some(&mut instance), None)?;
+            let instance = instance.ok_or("persisted route not found")?;
+
+            put_i32_property(&instance, "Metric1", metric)?;
+
+            conn.svc()
+                .PutInstance(&instance, WBEM_FLAG_UPDATE_ONLY.0, None, None)?;
+        }
+
+        Self::find_by_key(conn, destination, mask, next_hop)?
+            .filter(|route| route.Metric1 == Some(metric))
+            .ok_or_else(|| "RouteProvider rejected the updated metric".into())
+    }
+
+    /// Re-queries this route by its key tuple (`Destination`, `Mask`, `NextHop`), returning `None` if
+    /// no matching persisted route exists.
+    fn find_by_key(
+        conn: &WMIConnection,
+        destination: &str,
+        mask: &str,
+        next_hop: &str,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let mut filters = HashMap::new();
+        filters.insert(
+            "Destination".to_owned(),
+            FilterValue::String(destination.to_owned()),
+        );
+        filters.insert("Mask".to_owned(), FilterValue::String(mask.to_owned()));
+        filters.insert(
+            "NextHop".to_owned(),
+            FilterValue::String(next_hop.to_owned()),
+        );
+
+        let mut routes: Vec<Self> = conn.filtered_query(&filters)?;
+        Ok(routes.pop())
+    }
+
+    /// Returns this route's key tuple, erroring if any key property is missing.
+    fn key(&self) -> Result<(&str, &str, &str), Box<dyn std::error::Error>> {
+        Ok((
+            self.Destination.as_deref().ok_or("route has no Destination key")?,
+            self.Mask.as_deref().ok_or("route has no Mask key")?,
+            self.NextHop.as_deref().ok_or("route has no NextHop key")?,
+        ))
+    }
+
+    /// Builds this route's WQL object path, with each key property escaped for safe interpolation.
+    fn object_path(&self) -> Result<String, Box<dyn std::error::Error>> {
+        let (destination, mask, next_hop) = self.key()?;
+        Ok(format!(
+            "Win32_IP4PersistedRouteTable.Destination=\"{}\",Mask=\"{}\",NextHop=\"{}\"",
+            escape_wql_literal(destination),
+            escape_wql_literal(mask),
+            escape_wql_literal(next_hop),
+        ))
+    }
+}
+
+/// Sets a string-valued property on a freshly spawned or fetched `IWbemClassObject` instance.
+fn put_string_property(
+    obj: &IWbemClassObject,
+    name: &str,
+    value: &str,
+) -> windows::core::Result<()> {
+    let mut variant = VARIANT::from(value);
+    unsafe { obj.Put(&BSTR::from(name), 0, &mut variant, 0) }
+}
+
+/// Sets an `i32`-valued property on a freshly spawned or fetched `IWbemClassObject` instance.
+fn put_i32_property(obj: &IWbemClassObject, name: &str, value: i32) -> windows::core::Result<()> {
+    let mut variant = VARIANT::from(value);
+    unsafe { obj.Put(&BSTR::from(name), 0, &mut variant, 0) }
+}
+
+/// The `Win32_IP4RouteTable` WMI class represents information that governs the routing of network data packets.
 /// For example, Internet packets are usually sent to a gateway and local packets are routed directly by the 
 /// client computer. Administrators can use this information to trace problems associated with misrouted packets, 
 /// and also direct a computer to a new gateway as necessary. This class only represents the information shown 
@@ -205,3 +493,378 @@ pub struct Win32_IP4RouteTable {
     /// interpret such entries, examine the relevant ipRouteType object.
     pub Type: Option<u32>,
 }
+
+impl Win32_IP4RouteTable {
+    /// Decodes [`Win32_IP4RouteTable::Protocol`] into a [`RouteProtocol`], falling back to
+    /// [`RouteProtocol::Unknown`] for any value not covered by the `MIB_IPFORWARD_PROTO`
+    /// enumeration.
+    pub fn protocol(&self) -> Option<RouteProtocol> {
+        self.Protocol.map(RouteProtocol::from)
+    }
+
+    /// Decodes [`Win32_IP4RouteTable::Type`] into a [`RouteType`], falling back to
+    /// [`RouteType::Unknown`] for any value outside the documented range.
+    pub fn route_type(&self) -> Option<RouteType> {
+        self.Type.map(RouteType::from)
+    }
+}
+
+/// Routing mechanism through which a [`Win32_IP4RouteTable`] route was learned, mirroring the
+/// `MIB_IPFORWARD_PROTO` enumeration used by the IP Helper API.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RouteProtocol {
+    Other,
+    Local,
+    NetMgmt,
+    Icmp,
+    Egp,
+    Ggp,
+    Hello,
+    Rip,
+    IsIs,
+    EsIs,
+    CiscoIgrp,
+    BbnSpfIgp,
+    Ospf,
+    Bgp,
+    /// A raw value not covered by the `MIB_IPFORWARD_PROTO` enumeration above.
+    Unknown(u32),
+}
+
+impl From<u32> for RouteProtocol {
+    fn from(value: u32) -> Self {
+        match value {
+            1 => Self::Other,
+            2 => Self::Local,
+            3 => Self::NetMgmt,
+            4 => Self::Icmp,
+            5 => Self::Egp,
+            6 => Self::Ggp,
+            7 => Self::Hello,
+            8 => Self::Rip,
+            9 => Self::IsIs,
+            10 => Self::EsIs,
+            11 => Self::CiscoIgrp,
+            12 => Self::BbnSpfIgp,
+            13 => Self::Ospf,
+            14 => Self::Bgp,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+/// Type of a [`Win32_IP4RouteTable`] route. Values 3 (`Direct`) and 4 (`Indirect`) refer to direct
+/// and indirect routing in the IP architecture; `Invalid` disassociates the entry's destination
+/// from its route without removing it from the table.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RouteType {
+    Other,
+    Invalid,
+    Direct,
+    Indirect,
+    /// A raw value not covered by the documented range above.
+    Unknown(u32),
+}
+
+impl From<u32> for RouteType {
+    fn from(value: u32) -> Self {
+        match value {
+            1 => Self::Other,
+            2 => Self::Invalid,
+            3 => Self::Direct,
+            4 => Self::Indirect,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+/// Kind of change a [`RouteEventStream`] delivers for a given route.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RouteChangeKind {
+    /// A new route was added to the table.
+    Added,
+    /// An existing route was modified.
+    Modified,
+    /// A route was removed from the table.
+    Deleted,
+}
+
+/// A single routing-table change delivered by a [`RouteEventStream`].
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct RouteChange {
+    /// Whether the route was added, modified, or deleted.
+    pub kind: RouteChangeKind,
+    /// The route as it looked at the time of the change.
+    pub route: Win32_IP4RouteTable,
+}
+
+/// The raw shape of an `__InstanceOperationEvent` watching `Win32_IP4RouteTable`, as delivered by
+/// the `RouteEventProvider`.
+#[derive(Deserialize, Debug, Clone)]
+struct RouteInstanceEvent {
+    #[serde(rename = "__CLASS")]
+    class: String,
+    #[serde(rename = "TargetInstance")]
+    target_instance: Win32_IP4RouteTable,
+}
+
+/// A live subscription to IPv4 routing-table changes, built on the `RouteEventProvider` backing
+/// `Win32_IP4RouteTableEvent`.
+///
+/// Internally this issues an `__InstanceOperationEvent WITHIN n WHERE TargetInstance ISA
+/// 'Win32_IP4RouteTable'` notification query and pumps COM on a dedicated thread, forwarding typed
+/// [`RouteChange`]s to the caller over a channel so consumers can react to routing changes live
+/// instead of polling [`IP4RouteTables`].
+pub struct RouteEventStream {
+    receiver: Receiver<RouteChange>,
+    stop: Arc<AtomicBool>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl RouteEventStream {
+    /// Subscribes to IPv4 route changes, polling WMI for new events at most every `within_secs`
+    /// seconds.
+    ///
+    /// The worker drives the notification query's `IEnumWbemClassObject` directly (rather than
+    /// through `wmi::WMIConnection`'s notification iterator, whose underlying `Next` call blocks
+    /// with no timeout) so it can recheck the stop flag every [`NOTIFICATION_POLL_TIMEOUT_MS`]
+    /// and [`cancel`](Self::cancel) never has to wait for the next route change to unblock it.
+    pub fn subscribe(within_secs: u32) -> Result<Self, Box<dyn std::error::Error>> {
+        let (sender, receiver) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_worker = stop.clone();
+
+        let worker = std::thread::spawn(move || {
+            let Ok(com_con) = COMLibrary::new() else {
+                return;
+            };
+            let Ok(wmi_con) = WMIConnection::new(com_con) else {
+                return;
+            };
+
+            let query = format!(
+                "SELECT * FROM __InstanceOperationEvent WITHIN {within_secs} WHERE TargetInstance ISA 'Win32_IP4RouteTable'"
+            );
+
+            let Ok(enumerator) =
+                (unsafe { wmi_con.svc().ExecNotificationQuery(&BSTR::from("WQL"), &BSTR::from(query)) })
+            else {
+                return;
+            };
+
+            while !stop_worker.load(Ordering::Relaxed) {
+                let mut results: [Option<IWbemClassObject>; 1] = [None];
+                let mut returned = 0u32;
+
+                let next = unsafe {
+                    enumerator.Next(NOTIFICATION_POLL_TIMEOUT_MS, &mut results, &mut returned)
+                };
+                if next.is_err() {
+                    break;
+                }
+                if returned == 0 {
+                    continue;
+                }
+                let Some(obj) = results[0].take() else {
+                    continue;
+                };
+
+                let Ok(event) = from_wbem_class_obj::<RouteInstanceEvent>(&obj) else {
+                    continue;
+                };
+
+                let Some(kind) = route_change_kind(&event.class) else {
+                    continue;
+                };
+
+                let change = RouteChange {
+                    kind,
+                    route: event.target_instance,
+                };
+
+                if sender.send(change).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok(Self {
+            receiver,
+            stop,
+            worker: Some(worker),
+        })
+    }
+
+    /// Blocks until the next [`RouteChange`] arrives, returning `None` once the background worker
+    /// has exited (the notification query could not be set up, or enumerating it failed) and every
+    /// change it delivered has been received.
+    pub fn recv(&self) -> Option<RouteChange> {
+        self.receiver.recv().ok()
+    }
+
+    /// Signals the background thread to stop and waits for it to exit.
+    pub fn cancel(mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+impl Drop for RouteEventStream {
+    /// Safety net for callers who let the stream go out of scope (early return, `?`, panic)
+    /// instead of calling [`cancel`](Self::cancel): signals the worker to stop so it exits within
+    /// [`NOTIFICATION_POLL_TIMEOUT_MS`] instead of running for the life of the process. Doesn't
+    /// join the thread, since blocking in `drop` would make an un-cancelled stream hang the
+    /// dropping thread instead of just leaking a background one.
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Maps the `__CLASS` of an `__InstanceOperationEvent` to the [`RouteChangeKind`] it represents.
+fn route_change_kind(class: &str) -> Option<RouteChangeKind> {
+    match class {
+        "__InstanceCreationEvent" => Some(RouteChangeKind::Added),
+        "__InstanceModificationEvent" => Some(RouteChangeKind::Modified),
+        "__InstanceDeletionEvent" => Some(RouteChangeKind::Deleted),
+        _ => None,
+    }
+}
+
+/// Optional keyed input properties accepted by [`Win32_PingStatus::ping`], mapped into the `WHERE`
+/// clause of the query issued against `Win32_PingStatus`. Any field left as `None` is omitted from
+/// the query and falls back to the provider's own default.
+#[derive(Default, Debug, Clone)]
+pub struct PingOptions {
+    /// Time, in milliseconds, to wait for a reply before the request times out.
+    pub timeout: Option<u32>,
+    /// Size, in bytes, of the data buffer sent with the ICMP echo request.
+    pub buffer_size: Option<u32>,
+    /// Number of hops to record a route for. Must be between 0 and 9, inclusive.
+    pub record_route: Option<u32>,
+    /// Whether the source and destination address are resolved to host names.
+    pub resolve_address_names: Option<bool>,
+    /// Time-to-live value for the ICMP echo request.
+    pub ttl: Option<u32>,
+    /// Whether to prevent the ICMP echo request from being fragmented by gateways.
+    pub no_fragmentation: Option<bool>,
+}
+
+/// The `Win32_PingStatus` WMI class represents the values returned by the standard `ping` command.
+///
+/// Unlike the static route tables above, querying this class actively issues ICMP echoes, so
+/// instances are produced on demand through [`Win32_PingStatus::ping`] rather than snapshotted.
+///
+/// <https://learn.microsoft.com/en-us/previous-versions/windows/desktop/wmipicmp/win32-pingstatus>
+#[derive(Default, Deserialize, Serialize, Debug, Clone)]
+#[allow(non_snake_case)]
+#[allow(non_camel_case_types)]
+pub struct Win32_PingStatus {
+    /// Address, either as an IP address or a host name, that is the target of the ping.
+    pub Address: Option<String>,
+    /// Size, in bytes, of the buffer sent with the ping request.
+    pub BufferSize: Option<u32>,
+    /// Whether the ping request could not be fragmented by gateways on the route.
+    pub NoFragmentation: Option<bool>,
+    /// Protocol address associated with the address resolution, if any.
+    pub ProtocolAddress: Option<String>,
+    /// Host name associated with the protocol address, if resolved.
+    pub ProtocolAddressResolved: Option<String>,
+    /// Number of hops to record a route for.
+    pub RecordRoute: Option<u32>,
+    /// Time, in milliseconds, taken to receive a reply.
+    pub ResponseTime: Option<u32>,
+    /// Time-to-live value of the reply packet.
+    pub ResponseTimeToLive: Option<u32>,
+    /// Whether the source and destination addresses were resolved to host names.
+    pub ResolveAddressNames: Option<bool>,
+    /// Status of the ping request, e.g. success (0) or one of the documented ICMP error codes.
+    pub StatusCode: Option<u32>,
+    /// Time, in milliseconds, to wait for a reply before the request times out.
+    pub Timeout: Option<u32>,
+    /// Time-to-live value sent with the ping request.
+    pub TimeToLive: Option<u32>,
+}
+
+impl Win32_PingStatus {
+    /// Issues a ping against `address`, mapping `options` into the keyed `WHERE` clause
+    /// `Win32_PingStatus` expects its input properties through, e.g. `WHERE Address='...' AND
+    /// Timeout=...`.
+    pub fn ping(
+        conn: &WMIConnection,
+        address: &str,
+        options: PingOptions,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let escaped_address = escape_wql_literal(address);
+        let mut clauses = vec![format!("Address='{escaped_address}'")];
+
+        if let Some(timeout) = options.timeout {
+            clauses.push(format!("Timeout={timeout}"));
+        }
+        if let Some(buffer_size) = options.buffer_size {
+            clauses.push(format!("BufferSize={buffer_size}"));
+        }
+        if let Some(record_route) = options.record_route {
+            clauses.push(format!("RecordRoute={record_route}"));
+        }
+        if let Some(resolve_address_names) = options.resolve_address_names {
+            clauses.push(format!("ResolveAddressNames={resolve_address_names}"));
+        }
+        if let Some(ttl) = options.ttl {
+            clauses.push(format!("TimeToLive={ttl}"));
+        }
+        if let Some(no_fragmentation) = options.no_fragmentation {
+            clauses.push(format!("NoFragmentation={no_fragmentation}"));
+        }
+
+        let query = format!(
+            "SELECT * FROM Win32_PingStatus WHERE {}",
+            clauses.join(" AND ")
+        );
+
+        let mut results: Vec<Self> = conn.raw_query(&query)?;
+        results
+            .pop()
+            .ok_or_else(|| "Win32_PingStatus returned no result".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Inverse of [`escape_wql_literal`], used only to verify the escaping round-trips.
+    fn unescape_wql_literal(value: &str) -> String {
+        let mut result = String::with_capacity(value.len());
+        let mut chars = value.chars();
+        while let Some(c) = chars.next() {
+            if c == '\\' {
+                if let Some(escaped) = chars.next() {
+                    result.push(escaped);
+                }
+            } else {
+                result.push(c);
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn escape_wql_literal_round_trips_quotes_and_backslashes() {
+        let raw = r#"10.0.0.1\evil' OR "1"="1"#;
+        let escaped = escape_wql_literal(raw);
+
+        assert_eq!(unescape_wql_literal(&escaped), raw);
+
+        // Every quote left in the escaped output must be preceded by a backslash, so wrapping it
+        // in either a single- or double-quoted WQL literal can't be broken out of.
+        let mut previous = '\0';
+        for c in escaped.chars() {
+            if c == '\'' || c == '"' {
+                assert_eq!(previous, '\\', "unescaped quote in {escaped:?}");
+            }
+            previous = c;
+        }
+    }
+}